@@ -0,0 +1,171 @@
+// Multi-file concatenation: stacking rows from files that share a header,
+// joining columns from files side-by-side, and merging files with
+// differing column sets by building a union header. Mirrors the
+// `cat rows` / `cat columns` / `cat rowskey` commands found in xsv/qsv.
+
+use crate::error::CsvSliceError;
+use csv::ReaderBuilder;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// The result of [`concat_rows`] or [`concat_rows_key`]: the header row
+/// shared by (or synthesized from) the input files, plus every stacked
+/// data row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcatenatedRows {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// The result of [`concat_columns`]: rows formed by joining the
+/// corresponding row of each input file side-by-side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcatenatedColumns {
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Stacks rows from several CSV files that share the same header.
+///
+/// Only the first file's header is kept in the output; every subsequent
+/// file's header must match it exactly (same names, same order) or this
+/// returns a [`CsvSliceError::HeaderMismatch`]. This mirrors `xsv`/`qsv`'s
+/// `cat rows` command.
+///
+/// Use [`concat_rows_key`] instead when the input files don't share a
+/// common column set.
+pub fn concat_rows<P: AsRef<Path>>(paths: &[P]) -> Result<ConcatenatedRows, CsvSliceError> {
+    let mut headers: Option<Vec<String>> = None;
+    let mut rows = Vec::new();
+
+    for path in paths {
+        let file = File::open(path)?;
+        let mut rdr = ReaderBuilder::new().from_reader(BufReader::new(file));
+        let file_headers: Vec<String> = rdr.headers()?.iter().map(|s| s.to_string()).collect();
+
+        match &headers {
+            None => headers = Some(file_headers),
+            Some(expected) if expected == &file_headers => {}
+            Some(expected) => {
+                return Err(CsvSliceError::HeaderMismatch {
+                    expected: expected.join(","),
+                    found: file_headers.join(","),
+                })
+            }
+        }
+
+        for record in rdr.records() {
+            let record = record?;
+            rows.push(record.iter().map(|s| s.to_string()).collect());
+        }
+    }
+
+    Ok(ConcatenatedRows {
+        headers: headers.unwrap_or_default(),
+        rows,
+    })
+}
+
+/// Stacks rows from several CSV files that may have different column sets.
+///
+/// Scans every file's header first to build the union of column names in
+/// first-seen order, then emits each row against that union, writing an
+/// empty field where a source file lacks a given column. This mirrors
+/// `xsv`/`qsv`'s `cat rowskey` command.
+pub fn concat_rows_key<P: AsRef<Path>>(paths: &[P]) -> Result<ConcatenatedRows, CsvSliceError> {
+    let mut union_headers: Vec<String> = Vec::new();
+    let mut seen = HashSet::new();
+    let mut file_headers: Vec<Vec<String>> = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        let file = File::open(path)?;
+        let mut rdr = ReaderBuilder::new().from_reader(BufReader::new(file));
+        let headers: Vec<String> = rdr.headers()?.iter().map(|s| s.to_string()).collect();
+
+        for header in &headers {
+            if seen.insert(header.clone()) {
+                union_headers.push(header.clone());
+            }
+        }
+        file_headers.push(headers);
+    }
+
+    let mut rows = Vec::new();
+    for (path, headers) in paths.iter().zip(file_headers.iter()) {
+        let file = File::open(path)?;
+        let mut rdr = ReaderBuilder::new().from_reader(BufReader::new(file));
+
+        // Map each union column to this file's column index, if it has one
+        let indices: Vec<Option<usize>> = union_headers
+            .iter()
+            .map(|col| headers.iter().position(|h| h == col))
+            .collect();
+
+        for record in rdr.records() {
+            let record = record?;
+            let row: Vec<String> = indices
+                .iter()
+                .map(|idx| idx.and_then(|i| record.get(i)).unwrap_or("").to_string())
+                .collect();
+            rows.push(row);
+        }
+    }
+
+    Ok(ConcatenatedRows {
+        headers: union_headers,
+        rows,
+    })
+}
+
+/// Joins columns from several CSV files side-by-side, by row position.
+///
+/// By default every input must have the same number of data rows; if
+/// `pad` is `true`, files with fewer rows than the longest input are
+/// padded with empty fields instead of returning a
+/// [`CsvSliceError::RowCountMismatch`]. This mirrors `xsv`/`qsv`'s
+/// `cat columns` command, including its `--pad` flag.
+pub fn concat_columns<P: AsRef<Path>>(
+    paths: &[P],
+    pad: bool,
+) -> Result<ConcatenatedColumns, CsvSliceError> {
+    let mut file_widths = Vec::with_capacity(paths.len());
+    let mut file_rows: Vec<Vec<Vec<String>>> = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        let file = File::open(path)?;
+        let mut rdr = ReaderBuilder::new().from_reader(BufReader::new(file));
+        file_widths.push(rdr.headers()?.len());
+
+        let rows: Vec<Vec<String>> = rdr
+            .records()
+            .map(|record| record.map(|r| r.iter().map(|s| s.to_string()).collect()))
+            .collect::<Result<_, _>>()?;
+        file_rows.push(rows);
+    }
+
+    let min_rows = file_rows.iter().map(Vec::len).min().unwrap_or(0);
+    let max_rows = file_rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    if !pad && min_rows != max_rows {
+        return Err(CsvSliceError::RowCountMismatch {
+            min: min_rows,
+            max: max_rows,
+        });
+    }
+
+    let row_count = if pad { max_rows } else { min_rows };
+    let mut rows = Vec::with_capacity(row_count);
+    for i in 0..row_count {
+        let mut combined = Vec::new();
+        for (rows_for_file, &width) in file_rows.iter().zip(file_widths.iter()) {
+            match rows_for_file.get(i) {
+                Some(row) => combined.extend(row.iter().cloned()),
+                None => combined.extend(std::iter::repeat_n(String::new(), width)),
+            }
+        }
+        rows.push(combined);
+    }
+
+    Ok(ConcatenatedColumns { rows })
+}
@@ -11,20 +11,45 @@ use thiserror::Error;
 /// - `Csv`: Represents errors from the csv crate when parsing or processing CSV files
 /// - `Io`: Represents standard I/O errors that may occur when reading files
 /// - `ColumnNotFound`: A custom error that occurs when a requested column name doesn't exist in the CSV
+/// - `HeaderMismatch`: A custom error for `concat_rows` when input files don't share the same header
+/// - `RowCountMismatch`: A custom error for `concat_columns` when inputs have differing row counts and `pad` is off
+/// - `Deserialize`: A custom error for `extract_rows_typed` when a row doesn't match the target type
+/// - `InvalidIndex`: A custom error for `RowIndex::load` when a sidecar index file is malformed
 #[derive(Error, Debug)]
 pub enum CsvSliceError {
     /// Wraps errors from the csv crate
     /// The #[from] attribute automatically implements From<csv::Error> for CsvSliceError
     #[error("CSV error: {0}")]
     Csv(#[from] csv::Error),
-    
+
     /// Wraps standard I/O errors
     /// The #[from] attribute automatically implements From<std::io::Error> for CsvSliceError
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
-    
+
     /// Custom error for when a requested column name is not found in the CSV headers
     /// Contains the name of the column that was not found
     #[error("Column not found: {0}")]
-    ColumnNotFound(String)
+    ColumnNotFound(String),
+
+    /// Custom error for `concat_rows` when a file's header doesn't match
+    /// the header established by the first file
+    #[error("Header mismatch: expected \"{expected}\", found \"{found}\"")]
+    HeaderMismatch { expected: String, found: String },
+
+    /// Custom error for `concat_columns` when input files have differing
+    /// row counts and the `pad` flag was not set
+    #[error("Row count mismatch: shortest input has {min} rows, longest has {max}; pass `pad` to allow this")]
+    RowCountMismatch { min: usize, max: usize },
+
+    /// Custom error for `extract_rows_typed` when a sliced row fails to
+    /// deserialize into the caller's target type. `row` is the row's index
+    /// within the sliced file (0-based, excluding the header).
+    #[error("failed to deserialize row {row}: {source}")]
+    Deserialize { row: usize, source: csv::Error },
+
+    /// Custom error for `RowIndex::load` when a sidecar index file is
+    /// malformed (a missing file is reported as `Io`)
+    #[error("invalid row index: {0}")]
+    InvalidIndex(String),
 }
\ No newline at end of file
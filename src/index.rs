@@ -0,0 +1,161 @@
+// Precomputed byte-offset index for O(1) random row access, so slicing
+// row N doesn't require re-scanning every record before it. Mirrors the
+// random-access indexing xsv's `index` command builds via `csv_index`.
+
+use crate::error::CsvSliceError;
+use crate::options::CsvSliceOptions;
+use csv::{Position, StringRecord};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// The byte position of the start of every data record in a CSV file, so
+/// [`extract_rows_indexed`] can seek straight to `start` instead of
+/// scanning every record before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowIndex {
+    positions: Vec<Position>,
+}
+
+impl RowIndex {
+    /// Number of indexed rows.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the index covers zero rows.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Serializes the index to a sidecar file (one `byte,line,record` entry
+    /// per row) so repeated slices of the same file can skip rebuilding it.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), CsvSliceError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for pos in &self.positions {
+            writeln!(writer, "{},{},{}", pos.byte(), pos.line(), pos.record())?;
+        }
+        Ok(())
+    }
+
+    /// Loads a previously-saved index from a sidecar file written by [`RowIndex::save`].
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CsvSliceError> {
+        let contents = std::fs::read_to_string(path)?;
+
+        let mut positions = Vec::new();
+        for (line_no, line) in contents.lines().enumerate() {
+            let fields: Vec<&str> = line.split(',').collect();
+            let parsed = match fields.as_slice() {
+                [byte, line_num, record] => byte
+                    .parse::<u64>()
+                    .ok()
+                    .zip(line_num.parse::<u64>().ok())
+                    .zip(record.parse::<u64>().ok()),
+                _ => None,
+            };
+
+            let ((byte, line_num), record) = parsed.ok_or_else(|| {
+                CsvSliceError::InvalidIndex(format!("malformed entry on line {}", line_no + 1))
+            })?;
+
+            let mut pos = Position::new();
+            pos.set_byte(byte).set_line(line_num).set_record(record);
+            positions.push(pos);
+        }
+
+        Ok(RowIndex { positions })
+    }
+}
+
+/// Walks a CSV file once, recording the byte position where each data
+/// record begins.
+///
+/// # Example
+/// ```
+/// use csv_slice::{build_index, extract_rows_indexed};
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Name,Age\nAlice,30\nBob,25\nCharlie,40").unwrap();
+///
+/// let index = build_index(&file_path).unwrap();
+/// let rows = extract_rows_indexed(&file_path, &index, 1, 2).unwrap();
+/// assert_eq!(rows[0].get(0), Some("Bob"));
+/// ```
+pub fn build_index<P: AsRef<Path>>(path: P) -> Result<RowIndex, CsvSliceError> {
+    build_index_with_options(path, &CsvSliceOptions::default())
+}
+
+/// Like [`build_index`], but parses the file according to a custom
+/// [`CsvSliceOptions`] reader configuration.
+pub fn build_index_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &CsvSliceOptions,
+) -> Result<RowIndex, CsvSliceError> {
+    let file = File::open(path)?;
+    let mut rdr = options.reader_builder().from_reader(BufReader::new(file));
+
+    // Force the header row to be consumed before recording any positions,
+    // so position 0 below is the start of the first *data* record rather
+    // than the header.
+    if options.has_headers {
+        rdr.headers()?;
+    }
+
+    let mut positions = Vec::new();
+    let mut record = StringRecord::new();
+    loop {
+        let position = rdr.position().clone();
+        if !rdr.read_record(&mut record)? {
+            break;
+        }
+        positions.push(position);
+    }
+
+    Ok(RowIndex { positions })
+}
+
+/// Extracts a range of rows from a CSV file using a previously-built
+/// [`RowIndex`], seeking straight to `start` instead of scanning every
+/// record before it.
+pub fn extract_rows_indexed<P: AsRef<Path>>(
+    path: P,
+    index: &RowIndex,
+    start: usize,
+    end: usize,
+) -> Result<Vec<StringRecord>, CsvSliceError> {
+    extract_rows_indexed_with_options(path, index, start, end, &CsvSliceOptions::default())
+}
+
+/// Like [`extract_rows_indexed`], but parses the file according to a
+/// custom [`CsvSliceOptions`] reader configuration.
+pub fn extract_rows_indexed_with_options<P: AsRef<Path>>(
+    path: P,
+    index: &RowIndex,
+    start: usize,
+    end: usize,
+    options: &CsvSliceOptions,
+) -> Result<Vec<StringRecord>, CsvSliceError> {
+    let end = end.min(index.len());
+    let mut result = Vec::new();
+    if start >= end {
+        return Ok(result);
+    }
+
+    let file = File::open(path)?;
+    let mut rdr = options.reader_builder().from_reader(BufReader::new(file));
+    rdr.seek(index.positions[start].clone())?;
+
+    let mut record = StringRecord::new();
+    for _ in start..end {
+        if !rdr.read_record(&mut record)? {
+            break;
+        }
+        result.push(record.clone());
+    }
+
+    Ok(result)
+}
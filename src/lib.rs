@@ -89,10 +89,327 @@ mod tests {
         
         // Attempt to extract a column that doesn't exist ("Email")
         let result = super::extract_columns(path, &["Email"]);
-        
+
         // Verify that an error is returned
         assert!(result.is_err());
     }
+
+    /// Test for extract_rows_with_options with a semicolon delimiter
+    /// Verifies that a custom delimiter is honored when slicing rows.
+    #[test]
+    fn test_extract_rows_with_options() {
+        // Semicolon-delimited data instead of the default comma
+        let csv_data = "Name;Age\nAlice;30\nBob;25\nCharlie;40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let options = CsvSliceOptions::new().delimiter(b';');
+        let rows = super::extract_rows_with_options(path, 0, 2, &options).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get(0), Some("Alice"));
+        assert_eq!(rows[1].get(0), Some("Bob"));
+    }
+
+    /// Test for extract_columns_with_options on a headerless file
+    /// Verifies that columns can be selected by numeric index when
+    /// `has_headers` is false.
+    #[test]
+    fn test_extract_columns_with_options_headerless() {
+        // No header row, so columns must be selected by index
+        let csv_data = "Alice,30\nBob,25\nCharlie,40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let options = CsvSliceOptions::new().has_headers(false);
+        let columns = super::extract_columns_with_options(path, &["0"], &options).unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(columns[0][0], "Alice");
+        assert_eq!(columns[1][0], "Bob");
+        assert_eq!(columns[2][0], "Charlie");
+    }
+
+    /// Test for extract_rows_iter
+    /// Verifies that the lazy row iterator yields the same rows as
+    /// extract_rows without buffering the whole file up front.
+    #[test]
+    fn test_extract_rows_iter() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\nCharlie,40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let rows: Vec<_> = super::extract_rows_iter(path, 0, 2)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get(0), Some("Alice"));
+        assert_eq!(rows[1].get(0), Some("Bob"));
+    }
+
+    /// Test for extract_rows_iter_from_reader
+    /// Verifies that rows can be streamed from an arbitrary `Read` source
+    /// (e.g. stdin), not just a file path.
+    #[test]
+    fn test_extract_rows_iter_from_reader() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\nCharlie,40\n";
+        let cursor = std::io::Cursor::new(csv_data);
+
+        let rows: Vec<_> = super::extract_rows_iter_from_reader(cursor, 1, 3, &CsvSliceOptions::default())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get(0), Some("Bob"));
+        assert_eq!(rows[1].get(0), Some("Charlie"));
+    }
+
+    /// Test for extract_columns_iter
+    /// Verifies that the lazy column iterator yields the same data as
+    /// extract_columns.
+    #[test]
+    fn test_extract_columns_iter() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let columns: Vec<_> = super::extract_columns_iter(path, &["Name"])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0][0], "Alice");
+        assert_eq!(columns[1][0], "Bob");
+    }
+
+    /// Test for concat_rows
+    /// Verifies that rows from two files sharing the same header are
+    /// stacked, keeping only the first file's header.
+    #[test]
+    fn test_concat_rows() {
+        let mut file1 = NamedTempFile::new().unwrap();
+        write!(file1, "Name,Age\nAlice,30\n").unwrap();
+
+        let mut file2 = NamedTempFile::new().unwrap();
+        write!(file2, "Name,Age\nBob,25\n").unwrap();
+
+        let result = super::concat_rows(&[file1.path(), file2.path()]).unwrap();
+
+        assert_eq!(result.headers, vec!["Name", "Age"]);
+        assert_eq!(result.rows, vec![vec!["Alice", "30"], vec!["Bob", "25"]]);
+    }
+
+    /// Test for concat_rows with mismatched headers
+    /// Verifies that stacking files with different headers is rejected.
+    #[test]
+    fn test_concat_rows_header_mismatch() {
+        let mut file1 = NamedTempFile::new().unwrap();
+        write!(file1, "Name,Age\nAlice,30\n").unwrap();
+
+        let mut file2 = NamedTempFile::new().unwrap();
+        write!(file2, "Name,Email\nBob,bob@example.com\n").unwrap();
+
+        let result = super::concat_rows(&[file1.path(), file2.path()]);
+
+        assert!(result.is_err());
+    }
+
+    /// Test for concat_rows_key
+    /// Verifies that files with different column sets are merged onto the
+    /// union of their headers, leaving missing columns empty.
+    #[test]
+    fn test_concat_rows_key() {
+        let mut file1 = NamedTempFile::new().unwrap();
+        write!(file1, "Name,Age\nAlice,30\n").unwrap();
+
+        let mut file2 = NamedTempFile::new().unwrap();
+        write!(file2, "Name,Email\nBob,bob@example.com\n").unwrap();
+
+        let result = super::concat_rows_key(&[file1.path(), file2.path()]).unwrap();
+
+        assert_eq!(result.headers, vec!["Name", "Age", "Email"]);
+        assert_eq!(result.rows, vec![
+            vec!["Alice", "30", ""],
+            vec!["Bob", "", "bob@example.com"],
+        ]);
+    }
+
+    /// Test for concat_columns
+    /// Verifies that columns from two equally-sized files are joined
+    /// side-by-side by row position.
+    #[test]
+    fn test_concat_columns() {
+        let mut file1 = NamedTempFile::new().unwrap();
+        write!(file1, "Name\nAlice\nBob\n").unwrap();
+
+        let mut file2 = NamedTempFile::new().unwrap();
+        write!(file2, "Age\n30\n25\n").unwrap();
+
+        let result = super::concat_columns(&[file1.path(), file2.path()], false).unwrap();
+
+        assert_eq!(result.rows, vec![vec!["Alice", "30"], vec!["Bob", "25"]]);
+    }
+
+    /// Test for concat_columns with a shorter file and `pad` enabled
+    /// Verifies that missing rows are filled with empty fields instead of
+    /// erroring.
+    #[test]
+    fn test_concat_columns_pad() {
+        let mut file1 = NamedTempFile::new().unwrap();
+        write!(file1, "Name\nAlice\nBob\n").unwrap();
+
+        let mut file2 = NamedTempFile::new().unwrap();
+        write!(file2, "Age\n30\n").unwrap();
+
+        let result = super::concat_columns(&[file1.path(), file2.path()], true).unwrap();
+
+        assert_eq!(result.rows, vec![vec!["Alice", "30"], vec!["Bob", ""]]);
+    }
+
+    /// Test for extract_rows_typed
+    /// Verifies that sliced rows deserialize into a caller-defined struct
+    /// via the csv crate's serde integration.
+    #[test]
+    fn test_extract_rows_typed() {
+        #[derive(serde::Deserialize)]
+        struct Person {
+            name: String,
+            age: u32,
+        }
+
+        let csv_data = "name,age\nAlice,30\nBob,25\nCharlie,40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let people: Vec<Person> = super::extract_rows_typed(path, 0, 2).unwrap();
+
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[0].name, "Alice");
+        assert_eq!(people[0].age, 30);
+        assert_eq!(people[1].name, "Bob");
+    }
+
+    /// Test for extract_rows_typed deserialize error context
+    /// Verifies that a type mismatch is reported via
+    /// `CsvSliceError::Deserialize` with the offending row index.
+    #[test]
+    fn test_extract_rows_typed_deserialize_error() {
+        #[derive(serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Person {
+            name: String,
+            age: u32,
+        }
+
+        let csv_data = "name,age\nAlice,thirty\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let result: Result<Vec<Person>, _> = super::extract_rows_typed(path, 0, 1);
+
+        match result {
+            Err(CsvSliceError::Deserialize { row, .. }) => assert_eq!(row, 0),
+            _ => panic!("expected a CsvSliceError::Deserialize"),
+        }
+    }
+
+    /// Test for column_stats on a numeric column
+    /// Verifies count, distinct, and min/max/sum/mean are computed in a
+    /// single pass.
+    #[test]
+    fn test_column_stats_numeric() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\nCharlie,30\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let stats = super::column_stats(path, &["Age"]).unwrap();
+
+        assert_eq!(stats[0].count, 3);
+        assert_eq!(stats[0].distinct, 2);
+        let numeric = stats[0].numeric.unwrap();
+        assert_eq!(numeric.min, 25.0);
+        assert_eq!(numeric.max, 30.0);
+        assert_eq!(numeric.sum, 85.0);
+    }
+
+    /// Test for column_stats on a non-numeric column
+    /// Verifies that a column with non-numeric values has no numeric
+    /// summary but does have a frequency table.
+    #[test]
+    fn test_column_stats_non_numeric() {
+        let csv_data = "Name,Age\nAlice,30\nAlice,25\nBob,30\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let stats = super::column_stats(path, &["Name"]).unwrap();
+
+        assert!(stats[0].numeric.is_none());
+        assert_eq!(stats[0].distinct, 2);
+        assert_eq!(stats[0].top_values[0], ("Alice".to_string(), 2));
+    }
+
+    /// Test for build_index + extract_rows_indexed
+    /// Verifies that seeking via a precomputed index yields the same rows
+    /// as scanning with extract_rows.
+    #[test]
+    fn test_extract_rows_indexed() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\nCharlie,40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let index = super::build_index(path).unwrap();
+        assert_eq!(index.len(), 3);
+
+        let rows = super::extract_rows_indexed(path, &index, 1, 3).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].get(0), Some("Bob"));
+        assert_eq!(rows[1].get(0), Some("Charlie"));
+    }
+
+    /// Test for RowIndex::save / RowIndex::load
+    /// Verifies that an index saved to a sidecar file can be reloaded and
+    /// used to extract the same rows.
+    #[test]
+    fn test_row_index_save_and_load() {
+        let csv_data = "Name,Age\nAlice,30\nBob,25\nCharlie,40\n";
+
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "{}", csv_data).unwrap();
+        let path = file.path();
+
+        let index = super::build_index(path).unwrap();
+        let sidecar = NamedTempFile::new().unwrap();
+        index.save(sidecar.path()).unwrap();
+
+        let loaded = super::RowIndex::load(sidecar.path()).unwrap();
+        let rows = super::extract_rows_indexed(path, &loaded, 0, 1).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].get(0), Some("Alice"));
+    }
 }
 // END TESTS
 // Import required dependencies
@@ -100,7 +417,20 @@ use csv::StringRecord;  // For handling CSV records
 use std::fs::File;      // For file operations
 use std::io::BufReader; // For buffered reading from files
 mod error;              // Import the error module
+mod options;            // Import the options module
+mod concat;              // Import the multi-file concatenation module
+mod typed;               // Import the typed (serde) extraction module
+mod stats;               // Import the column statistics module
+mod index;               // Import the byte-offset row index module
 pub use crate::error::CsvSliceError; // Re-export the CsvSliceError type
+pub use crate::options::CsvSliceOptions; // Re-export the CsvSliceOptions builder
+pub use crate::concat::{concat_columns, concat_rows, concat_rows_key, ConcatenatedColumns, ConcatenatedRows};
+pub use crate::typed::{extract_rows_typed, extract_rows_typed_with_options};
+pub use crate::stats::{column_stats, ColumnStats, NumericStats};
+pub use crate::index::{
+    build_index, build_index_with_options, extract_rows_indexed, extract_rows_indexed_with_options,
+    RowIndex,
+};
 
 /// Extracts a range of rows from a CSV file.
 ///
@@ -135,33 +465,49 @@ pub fn extract_rows<P: AsRef<std::path::Path>>(
     start: usize,
     end: usize,
 ) -> Result<Vec<StringRecord>, CsvSliceError> {
-    // Open the file at the specified path
-    let file = File::open(path)?;
-    
-    // Create a CSV reader with buffered IO for better performance
-    let mut rdr = csv::Reader::from_reader(BufReader::new(file));
-    
-    // Initialize an empty vector to store the results
-    let mut result = Vec::new();
-
-    // Iterate through all records in the CSV file
-    for (i, record) in rdr.records().enumerate() {
-        // Parse the record, propagating any errors
-        let record = record?;
-        
-        // If the current index is within our desired range, add it to the results
-        if i >= start && i < end {
-            result.push(record);
-        }
-        
-        // If we've reached the end of our desired range, stop processing
-        if i >= end {
-            break;
-        }
-    }
-    
-    // Return the collected results
-    Ok(result)
+    extract_rows_with_options(path, start, end, &CsvSliceOptions::default())
+}
+
+/// Extracts a range of rows from a CSV file using a custom reader configuration.
+///
+/// Behaves like [`extract_rows`], but parses the file according to the
+/// delimiter, quote character, header handling, and trimming described by
+/// `options`. This is how callers handle tab- or semicolon-separated data,
+/// embedded quotes or CRLF line endings, and headerless files.
+///
+/// # Parameters
+/// * `path` - Path to the CSV file. Can be any type that can be converted to a Path.
+/// * `start` - The index of the first row to extract (0-based, excluding header).
+/// * `end` - The index after the last row to extract (exclusive).
+/// * `options` - The reader configuration to use.
+///
+/// # Returns
+/// * `Result<Vec<StringRecord>, CsvSliceError>` - A vector of StringRecords on success,
+///   or a CsvSliceError on failure.
+///
+/// # Example
+/// ```
+/// use csv_slice::{extract_rows_with_options, CsvSliceOptions};
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.tsv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Name\tAge\nAlice\t30\nBob\t25").unwrap();
+///
+/// let options = CsvSliceOptions::new().delimiter(b'\t');
+/// let rows = extract_rows_with_options(&file_path, 0, 2, &options).unwrap();
+/// assert_eq!(rows.len(), 2);
+/// assert_eq!(rows[0].get(0), Some("Alice"));
+/// ```
+pub fn extract_rows_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    start: usize,
+    end: usize,
+    options: &CsvSliceOptions,
+) -> Result<Vec<StringRecord>, CsvSliceError> {
+    extract_rows_iter_with_options(path, start, end, options)?.collect()
 }
 
 /// Extracts specific columns from a CSV file by column name.
@@ -196,40 +542,183 @@ pub fn extract_columns<P: AsRef<std::path::Path>>(
     path: P,
     columns: &[&str],
 ) -> Result<Vec<Vec<String>>, CsvSliceError> {
-    // Open the file at the specified path
+    extract_columns_with_options(path, columns, &CsvSliceOptions::default())
+}
+
+/// Extracts specific columns from a CSV file using a custom reader configuration.
+///
+/// Behaves like [`extract_columns`], but parses the file according to the
+/// delimiter, quote character, header handling, and trimming described by
+/// `options`. When `options` was built with `has_headers(false)`, there is no header
+/// row to resolve names against, so each entry in `columns` is instead
+/// parsed as a 0-based column index.
+///
+/// # Parameters
+/// * `path` - Path to the CSV file. Can be any type that can be converted to a Path.
+/// * `columns` - Column names (or, for headerless files, numeric indices as strings) to extract.
+/// * `options` - The reader configuration to use.
+///
+/// # Returns
+/// * `Result<Vec<Vec<String>>, CsvSliceError>` - A vector of vectors containing the
+///   extracted column data on success, or a CsvSliceError on failure.
+///
+/// # Example
+/// ```
+/// use csv_slice::{extract_columns_with_options, CsvSliceOptions};
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Alice,30\nBob,25").unwrap();
+///
+/// let options = CsvSliceOptions::new().has_headers(false);
+/// let data = extract_columns_with_options(&file_path, &["0"], &options).unwrap();
+/// assert_eq!(data[0][0], "Alice");
+/// ```
+pub fn extract_columns_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    columns: &[&str],
+    options: &CsvSliceOptions,
+) -> Result<Vec<Vec<String>>, CsvSliceError> {
+    extract_columns_iter_with_options(path, columns, options)?.collect()
+}
+
+/// Lazily extracts a range of rows from a CSV file.
+///
+/// Unlike [`extract_rows`], this does not buffer the whole file into a
+/// `Vec` up front: records are read from disk one at a time as the
+/// returned iterator is advanced, and reading stops as soon as index `end`
+/// is reached so the rest of a huge file is never touched.
+///
+/// # Example
+/// ```
+/// use csv_slice::extract_rows_iter;
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Name,Age\nAlice,30\nBob,25\nCharlie,40").unwrap();
+///
+/// let rows: Vec<_> = extract_rows_iter(&file_path, 0, 2).unwrap()
+///     .collect::<Result<_, _>>().unwrap();
+/// assert_eq!(rows.len(), 2);
+/// ```
+pub fn extract_rows_iter<P: AsRef<std::path::Path>>(
+    path: P,
+    start: usize,
+    end: usize,
+) -> Result<impl Iterator<Item = Result<StringRecord, CsvSliceError>>, CsvSliceError> {
+    extract_rows_iter_with_options(path, start, end, &CsvSliceOptions::default())
+}
+
+/// Like [`extract_rows_iter`], but parses the file according to a custom
+/// [`CsvSliceOptions`] reader configuration.
+pub fn extract_rows_iter_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    start: usize,
+    end: usize,
+    options: &CsvSliceOptions,
+) -> Result<impl Iterator<Item = Result<StringRecord, CsvSliceError>>, CsvSliceError> {
     let file = File::open(path)?;
-    
-    // Create a CSV reader with buffered IO for better performance
-    let mut rdr = csv::Reader::from_reader(BufReader::new(file));
-    
-    // Get the headers from the CSV file and clone them for later use
-    let headers = rdr.headers()?.clone();
-
-    // Find the indices of the requested columns in the header row
-    let indices: Vec<_> = columns
-        .iter()
-        .map(|&col| headers.iter().position(|h| h == col)
-            // If a column is not found, return a ColumnNotFound error
-            .ok_or_else(|| CsvSliceError::ColumnNotFound(col.to_string())))
-        .collect::<Result<_, _>>()?;
-
-    // Initialize an empty vector to store the results
-    let mut result = Vec::new();
-    
-    // Process each record in the CSV file
-    for record in rdr.records() {
-        // Parse the record, propagating any errors
+    extract_rows_iter_from_reader(BufReader::new(file), start, end, options)
+}
+
+/// Like [`extract_rows_iter`], but reads from any `Read` source instead of
+/// a file path, so callers can pass e.g. `std::io::stdin()` to stream rows
+/// from a pipe.
+pub fn extract_rows_iter_from_reader<R: std::io::Read>(
+    reader: R,
+    start: usize,
+    end: usize,
+    options: &CsvSliceOptions,
+) -> Result<impl Iterator<Item = Result<StringRecord, CsvSliceError>>, CsvSliceError> {
+    let rdr = options.reader_builder().from_reader(reader);
+
+    // Stop pulling records from the underlying reader as soon as `end` is
+    // reached, then drop everything before `start`
+    Ok(rdr
+        .into_records()
+        .enumerate()
+        .take_while(move |(i, _)| *i < end)
+        .filter(move |(i, _)| *i >= start)
+        .map(|(_, record)| record.map_err(CsvSliceError::from)))
+}
+
+/// Lazily extracts specific columns from a CSV file by column name.
+///
+/// Unlike [`extract_columns`], this does not buffer the whole file into a
+/// `Vec` up front: rows are read from disk one at a time as the returned
+/// iterator is advanced.
+///
+/// # Example
+/// ```
+/// use csv_slice::extract_columns_iter;
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Name,Age\nAlice,30\nBob,25").unwrap();
+///
+/// let rows: Vec<_> = extract_columns_iter(&file_path, &["Name"]).unwrap()
+///     .collect::<Result<_, _>>().unwrap();
+/// assert_eq!(rows.len(), 2);
+/// ```
+pub fn extract_columns_iter<P: AsRef<std::path::Path>>(
+    path: P,
+    columns: &[&str],
+) -> Result<impl Iterator<Item = Result<Vec<String>, CsvSliceError>>, CsvSliceError> {
+    extract_columns_iter_with_options(path, columns, &CsvSliceOptions::default())
+}
+
+/// Like [`extract_columns_iter`], but parses the file according to a custom
+/// [`CsvSliceOptions`] reader configuration.
+pub fn extract_columns_iter_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    columns: &[&str],
+    options: &CsvSliceOptions,
+) -> Result<impl Iterator<Item = Result<Vec<String>, CsvSliceError>>, CsvSliceError> {
+    let file = File::open(path)?;
+    extract_columns_iter_from_reader(BufReader::new(file), columns, options)
+}
+
+/// Like [`extract_columns_iter`], but reads from any `Read` source instead
+/// of a file path, so callers can pass e.g. `std::io::stdin()` to stream
+/// columns from a pipe.
+pub fn extract_columns_iter_from_reader<R: std::io::Read>(
+    reader: R,
+    columns: &[&str],
+    options: &CsvSliceOptions,
+) -> Result<impl Iterator<Item = Result<Vec<String>, CsvSliceError>>, CsvSliceError> {
+    let mut rdr = options.reader_builder().from_reader(reader);
+
+    // Resolve the requested columns to indices, either by header name or,
+    // for headerless files, by parsing them as numeric indices directly
+    let indices: Vec<usize> = if options.has_headers {
+        let headers = rdr.headers()?.clone();
+        columns
+            .iter()
+            .map(|&col| headers.iter().position(|h| h == col)
+                // If a column is not found, return a ColumnNotFound error
+                .ok_or_else(|| CsvSliceError::ColumnNotFound(col.to_string())))
+            .collect::<Result<_, _>>()?
+    } else {
+        columns
+            .iter()
+            .map(|&col| col.parse::<usize>()
+                .map_err(|_| CsvSliceError::ColumnNotFound(col.to_string())))
+            .collect::<Result<_, _>>()?
+    };
+
+    Ok(rdr.into_records().map(move |record| {
         let record = record?;
-        
-        // Extract the values from the requested columns for this record
-        let row: Vec<String> = indices.iter()
+        Ok(indices.iter()
             .map(|&i| record.get(i).unwrap_or("").to_string())
-            .collect();
-            
-        // Add the extracted values to the result
-        result.push(row);
-    }
-    
-    // Return the collected results
-    Ok(result)
+            .collect())
+    }))
 }
\ No newline at end of file
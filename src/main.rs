@@ -4,7 +4,7 @@ use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "csv-slice")]
-#[command(about = "Extract rows or columns from CSV files", long_about = None)]
+#[command(about = "Extract rows, columns, or stats from CSV files", long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
@@ -13,6 +13,7 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     Rows {
+        /// Path to the CSV file, or `-` to read from stdin
         #[arg(short, long)]
         input: String,
         #[arg(short, long)]
@@ -21,31 +22,89 @@ enum Commands {
         end: usize,
     },
     Columns {
+        /// Path to the CSV file, or `-` to read from stdin
         #[arg(short, long)]
         input: String,
         #[arg(short, long)]
         columns: Vec<String>,
     },
+    Stats {
+        #[arg(short, long)]
+        input: String,
+        #[arg(short, long)]
+        columns: Vec<String>,
+    },
+}
+
+use csv::StringRecord;
+use csv_slice::{CsvSliceError, CsvSliceOptions};
+
+type RowResults<'a> = Box<dyn Iterator<Item = Result<StringRecord, CsvSliceError>> + 'a>;
+type ColumnResults<'a> = Box<dyn Iterator<Item = Result<Vec<String>, CsvSliceError>> + 'a>;
+
+/// Resolves `input` to a lazy row iterator, reading from stdin when `input`
+/// is `-` instead of opening it as a file path.
+fn rows_source(input: &str, start: usize, end: usize) -> Result<RowResults<'_>, CsvSliceError> {
+    if input == "-" {
+        let rows = csv_slice::extract_rows_iter_from_reader(
+            std::io::stdin(),
+            start,
+            end,
+            &CsvSliceOptions::default(),
+        )?;
+        Ok(Box::new(rows))
+    } else {
+        Ok(Box::new(csv_slice::extract_rows_iter(input, start, end)?))
+    }
 }
 
-use csv_slice::CsvSliceError;
+/// Resolves `input` to a lazy column iterator, reading from stdin when
+/// `input` is `-` instead of opening it as a file path.
+fn columns_source<'a>(input: &'a str, columns: &'a [&str]) -> Result<ColumnResults<'a>, CsvSliceError> {
+    if input == "-" {
+        let cols = csv_slice::extract_columns_iter_from_reader(
+            std::io::stdin(),
+            columns,
+            &CsvSliceOptions::default(),
+        )?;
+        Ok(Box::new(cols))
+    } else {
+        Ok(Box::new(csv_slice::extract_columns_iter(input, columns)?))
+    }
+}
 
 fn main() -> Result<(), CsvSliceError> {
     let cli = Cli::parse();
 
     match &cli.command {
         Commands::Rows { input, start, end } => {
-            let rows = csv_slice::extract_rows(input, *start, *end)?;
-            for row in rows {
+            for row in rows_source(input, *start, *end)? {
+                let row = row?;
                 println!("{}", row.iter().collect::<Vec<_>>().join(","));
             }
         }
         Commands::Columns { input, columns } => {
-            let cols = csv_slice::extract_columns(input, &columns.iter().map(|s| s.as_str()).collect::<Vec<_>>())?;
-            for row in cols {
+            let columns: Vec<&str> = columns.iter().map(|s| s.as_str()).collect();
+            for row in columns_source(input, &columns)? {
+                let row = row?;
                 println!("{}", row.join(","));
             }
         }
+        Commands::Stats { input, columns } => {
+            let columns: Vec<&str> = columns.iter().map(|s| s.as_str()).collect();
+            for stat in csv_slice::column_stats(input, &columns)? {
+                println!("{}: count={}, distinct={}", stat.name, stat.count, stat.distinct);
+                if let Some(numeric) = stat.numeric {
+                    println!(
+                        "  min={}, max={}, sum={}, mean={}",
+                        numeric.min, numeric.max, numeric.sum, numeric.mean
+                    );
+                }
+                for (value, freq) in &stat.top_values {
+                    println!("  {}: {}", value, freq);
+                }
+            }
+        }
     }
     Ok(())
 }
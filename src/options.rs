@@ -0,0 +1,83 @@
+// Configuration for building a customized CSV reader, allowing callers to
+// handle non-comma-delimited files, embedded-quote/CRLF data, and headerless
+// CSVs instead of csv-slice's comma/double-quote/header-row defaults.
+
+use csv::{ReaderBuilder, Trim};
+
+/// Builder-style configuration for the `*_with_options` variants of
+/// `extract_rows` and `extract_columns`.
+///
+/// # Example
+/// ```
+/// use csv_slice::CsvSliceOptions;
+///
+/// let options = CsvSliceOptions::new()
+///     .delimiter(b';')
+///     .quote(b'\'')
+///     .has_headers(false)
+///     .trim(true);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvSliceOptions {
+    pub(crate) delimiter: u8,
+    pub(crate) quote: u8,
+    pub(crate) has_headers: bool,
+    pub(crate) trim: bool,
+}
+
+impl Default for CsvSliceOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            has_headers: true,
+            trim: false,
+        }
+    }
+}
+
+impl CsvSliceOptions {
+    /// Creates a new `CsvSliceOptions` with the csv crate's defaults:
+    /// comma-delimited, double-quote-quoted, with headers, no trimming.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field delimiter (defaults to `,`).
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the quote character (defaults to `"`).
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets whether the first record should be treated as a header row
+    /// (defaults to `true`). When set to `false`, column selection by name
+    /// is unavailable; use numeric indices instead.
+    pub fn has_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets whether leading/trailing whitespace should be trimmed from
+    /// fields and headers (defaults to `false`).
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Builds a `csv::ReaderBuilder` configured from these options.
+    pub(crate) fn reader_builder(&self) -> ReaderBuilder {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .has_headers(self.has_headers)
+            .trim(if self.trim { Trim::All } else { Trim::None });
+        builder
+    }
+}
@@ -0,0 +1,137 @@
+// Per-column statistics and frequency summaries, computed in a single
+// streaming pass over the file. Mirrors the `stats`/`frequency` commands
+// in xsv/qsv.
+
+use crate::error::CsvSliceError;
+use csv::ReaderBuilder;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Number of entries kept in a column's frequency table.
+const TOP_N: usize = 10;
+
+/// Min/max/sum/mean for a column whose non-empty values all parsed as numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericStats {
+    pub min: f64,
+    pub max: f64,
+    pub sum: f64,
+    pub mean: f64,
+}
+
+/// Summary statistics for a single requested column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStats {
+    pub name: String,
+    /// Number of non-empty values seen for this column
+    pub count: usize,
+    /// Number of distinct non-empty values seen for this column
+    pub distinct: usize,
+    /// `Some` when every non-empty value in the column parsed as an `f64`
+    pub numeric: Option<NumericStats>,
+    /// The most common values, most frequent first (ties broken alphabetically)
+    pub top_values: Vec<(String, usize)>,
+}
+
+/// Computes per-column statistics for the requested columns in a single
+/// streaming pass: a count of non-empty values, the number of distinct
+/// values, min/max/sum/mean when every value parses as a number, and a
+/// top-10 frequency table of the most common string values.
+///
+/// # Parameters
+/// * `path` - Path to the CSV file. Can be any type that can be converted to a Path.
+/// * `columns` - Array of column names to summarize.
+///
+/// # Example
+/// ```
+/// use csv_slice::column_stats;
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "Name,Age\nAlice,30\nBob,25\nCharlie,30").unwrap();
+///
+/// let stats = column_stats(&file_path, &["Age"]).unwrap();
+/// assert_eq!(stats[0].count, 3);
+/// assert_eq!(stats[0].numeric.unwrap().mean, 85.0 / 3.0);
+/// ```
+pub fn column_stats<P: AsRef<Path>>(
+    path: P,
+    columns: &[&str],
+) -> Result<Vec<ColumnStats>, CsvSliceError> {
+    let file = File::open(path)?;
+    let mut rdr = ReaderBuilder::new().from_reader(BufReader::new(file));
+
+    // Resolve the requested column names to indices, same lookup extract_columns uses
+    let headers = rdr.headers()?.clone();
+    let indices: Vec<usize> = columns
+        .iter()
+        .map(|&col| {
+            headers
+                .iter()
+                .position(|h| h == col)
+                .ok_or_else(|| CsvSliceError::ColumnNotFound(col.to_string()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut counts = vec![0usize; indices.len()];
+    let mut sums = vec![0f64; indices.len()];
+    let mut mins = vec![f64::INFINITY; indices.len()];
+    let mut maxs = vec![f64::NEG_INFINITY; indices.len()];
+    let mut all_numeric = vec![true; indices.len()];
+    let mut frequencies: Vec<HashMap<String, usize>> = vec![HashMap::new(); indices.len()];
+
+    for record in rdr.records() {
+        let record = record?;
+        for (col, &field_idx) in indices.iter().enumerate() {
+            let value = record.get(field_idx).unwrap_or("");
+            if value.is_empty() {
+                continue;
+            }
+
+            counts[col] += 1;
+            *frequencies[col].entry(value.to_string()).or_insert(0) += 1;
+
+            if all_numeric[col] {
+                match value.parse::<f64>() {
+                    Ok(n) => {
+                        sums[col] += n;
+                        mins[col] = mins[col].min(n);
+                        maxs[col] = maxs[col].max(n);
+                    }
+                    Err(_) => all_numeric[col] = false,
+                }
+            }
+        }
+    }
+
+    let mut result = Vec::with_capacity(indices.len());
+    for (col, &name) in columns.iter().enumerate() {
+        let count = counts[col];
+        let numeric = (all_numeric[col] && count > 0).then(|| NumericStats {
+            min: mins[col],
+            max: maxs[col],
+            sum: sums[col],
+            mean: sums[col] / count as f64,
+        });
+
+        let distinct = frequencies[col].len();
+        let mut top_values: Vec<(String, usize)> = frequencies[col].drain().collect();
+        top_values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_values.truncate(TOP_N);
+
+        result.push(ColumnStats {
+            name: name.to_string(),
+            count,
+            distinct,
+            numeric,
+            top_values,
+        });
+    }
+
+    Ok(result)
+}
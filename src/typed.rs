@@ -0,0 +1,76 @@
+// Typed row extraction built on the csv crate's serde integration, so
+// callers can deserialize a sliced row range directly into a struct whose
+// fields map to header names instead of re-parsing `StringRecord`s by hand.
+
+use crate::error::CsvSliceError;
+use crate::options::CsvSliceOptions;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Extracts a range of rows from a CSV file, deserializing each one into `T`.
+///
+/// # Parameters
+/// * `path` - Path to the CSV file. Can be any type that can be converted to a Path.
+/// * `start` - The index of the first row to extract (0-based, excluding header).
+/// * `end` - The index after the last row to extract (exclusive).
+///
+/// # Returns
+/// * `Result<Vec<T>, CsvSliceError>` - The deserialized rows on success, or a
+///   `CsvSliceError::Deserialize` if a row doesn't match `T`.
+///
+/// # Example
+/// ```
+/// use csv_slice::extract_rows_typed;
+/// use serde::Deserialize;
+/// use std::io::Write;
+/// use std::fs::File;
+///
+/// #[derive(Deserialize)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let temp_dir = tempfile::tempdir().unwrap();
+/// let file_path = temp_dir.path().join("sample.csv");
+/// let mut file = File::create(&file_path).unwrap();
+/// writeln!(file, "name,age\nAlice,30\nBob,25").unwrap();
+///
+/// let people: Vec<Person> = extract_rows_typed(&file_path, 0, 2).unwrap();
+/// assert_eq!(people[0].name, "Alice");
+/// assert_eq!(people[0].age, 30);
+/// ```
+pub fn extract_rows_typed<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+    start: usize,
+    end: usize,
+) -> Result<Vec<T>, CsvSliceError> {
+    extract_rows_typed_with_options(path, start, end, &CsvSliceOptions::default())
+}
+
+/// Like [`extract_rows_typed`], but parses the file according to a custom
+/// [`CsvSliceOptions`] reader configuration.
+pub fn extract_rows_typed_with_options<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+    start: usize,
+    end: usize,
+    options: &CsvSliceOptions,
+) -> Result<Vec<T>, CsvSliceError> {
+    let file = File::open(path)?;
+    let mut rdr = options.reader_builder().from_reader(BufReader::new(file));
+
+    let mut result = Vec::new();
+    for (i, record) in rdr.deserialize::<T>().enumerate() {
+        if i >= end {
+            break;
+        }
+        if i >= start {
+            let value = record.map_err(|source| CsvSliceError::Deserialize { row: i, source })?;
+            result.push(value);
+        }
+    }
+
+    Ok(result)
+}